@@ -0,0 +1,253 @@
+/**
+ * OpenAI Codex Integration - Command Capability Manifest
+ *
+ * Turns the ad-hoc list of exported Codex commands into an auditable, machine-
+ * readable permission surface, borrowing the capability/permission-set idea
+ * from Tauri v2's ACL. Each exported command is tagged with a permission
+ * category and a human-readable summary, and the frontend can supply an
+ * enabled-category set so that, for example, a "review-only" workspace can
+ * register every command but have destructive ones return `PermissionDenied`.
+ */
+
+use std::collections::HashSet;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+
+/// Permission category a command belongs to. A command has exactly one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Capability {
+    /// Reads state without side effects (availability, listing, history).
+    Read,
+    /// Spawns or cancels a Codex process, or runs another active operation
+    /// (provider connection check, session conversion).
+    Execute,
+    /// Mutates the persisted Codex/provider/scope configuration, the rewind
+    /// prompt records, or the in-memory enabled-category set.
+    MutateConfig,
+    /// Performs a destructive git operation (revert/truncate/rewind).
+    DestructiveGit,
+    /// Irreversibly deletes stored data that is not a git operation
+    /// (e.g. deleting a session record).
+    Destructive,
+    /// Reads or writes provider credentials.
+    SecretAccess,
+}
+
+/// One row of the capability manifest.
+#[derive(Debug, Clone, Serialize)]
+pub struct CommandCapability {
+    /// Command name as registered with Tauri.
+    pub command: &'static str,
+    /// The permission category this command requires.
+    pub capability: Capability,
+    /// One-line description of what the command does.
+    pub summary: &'static str,
+}
+
+/// Error returned when a command is invoked but its category is not enabled.
+#[derive(Debug, Clone, Serialize)]
+pub struct PermissionDenied {
+    pub command: String,
+    pub capability: Capability,
+}
+
+impl std::fmt::Display for PermissionDenied {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "permission denied: '{}' requires capability '{:?}' which is not enabled",
+            self.command, self.capability
+        )
+    }
+}
+
+impl std::error::Error for PermissionDenied {}
+
+impl From<PermissionDenied> for String {
+    fn from(e: PermissionDenied) -> String {
+        e.to_string()
+    }
+}
+
+/// The full capability manifest for the Codex command surface.
+///
+/// Entries for the rewind and conversion subsystems are only present when their
+/// respective features are enabled, so the manifest always matches the set of
+/// commands actually registered.
+pub const CAPABILITIES: &[CommandCapability] = &[
+    // Session management
+    cap("execute_codex", Capability::Execute, "Spawn a new Codex session"),
+    cap("resume_codex", Capability::Execute, "Resume a specific Codex session"),
+    cap("resume_last_codex", Capability::Execute, "Resume the most recent Codex session"),
+    cap("cancel_codex", Capability::Execute, "Cancel a running Codex session"),
+    cap("list_codex_sessions", Capability::Read, "List known Codex sessions"),
+    cap("load_codex_session_history", Capability::Read, "Load a session's message history"),
+    cap("delete_codex_session", Capability::Destructive, "Delete a stored Codex session"),
+    // Configuration
+    cap("check_codex_availability", Capability::Read, "Check whether the Codex CLI is available"),
+    cap("set_custom_codex_path", Capability::MutateConfig, "Set a custom path to the Codex binary"),
+    cap("get_codex_path", Capability::Read, "Get the resolved Codex binary path"),
+    cap("clear_custom_codex_path", Capability::MutateConfig, "Clear the custom Codex binary path"),
+    cap("validate_codex_path_cmd", Capability::Read, "Validate a candidate Codex binary path"),
+    cap("get_codex_mode_config", Capability::Read, "Read the Codex execution-mode config"),
+    cap("set_codex_mode_config", Capability::MutateConfig, "Write the Codex execution-mode config"),
+    // Provider management
+    cap("get_codex_provider_presets", Capability::Read, "List built-in provider presets"),
+    cap("get_current_codex_config", Capability::Read, "Read the active provider config"),
+    cap("switch_codex_provider", Capability::MutateConfig, "Switch the active provider"),
+    cap("add_codex_provider_config", Capability::MutateConfig, "Add a provider config"),
+    cap("update_codex_provider_config", Capability::MutateConfig, "Update a provider config"),
+    cap("delete_codex_provider_config", Capability::MutateConfig, "Delete a provider config"),
+    cap("clear_codex_provider_config", Capability::MutateConfig, "Clear all provider config"),
+    cap("test_codex_provider_connection", Capability::Execute, "Check a provider has a stored key and base URL"),
+    // Provider secret storage
+    cap("store_codex_provider_secret", Capability::SecretAccess, "Store a provider API key in the keychain"),
+    cap("get_codex_provider_secret_exists", Capability::Read, "Check whether a provider secret exists"),
+    cap("clear_codex_provider_secret", Capability::SecretAccess, "Remove a provider secret from the keychain"),
+    cap("migrate_codex_provider_secrets", Capability::SecretAccess, "Migrate inline config secrets into the keychain"),
+    // Filesystem scope
+    cap("get_codex_scope", Capability::Read, "Read the filesystem scope config"),
+    cap("set_codex_scope", Capability::MutateConfig, "Write the filesystem scope config"),
+    // Capabilities
+    cap("list_codex_capabilities", Capability::Read, "List the Codex command capability manifest"),
+    cap("check_codex_permission", Capability::Read, "Check whether a command is permitted"),
+    cap("set_codex_enabled_capabilities", Capability::MutateConfig, "Set the enabled permission categories"),
+    // Git operations / rewind
+    #[cfg(feature = "codex-rewind")]
+    cap("get_codex_prompt_list", Capability::Read, "List recorded prompts for a session"),
+    #[cfg(feature = "codex-rewind")]
+    cap("check_codex_rewind_capabilities", Capability::Read, "Check git rewind availability"),
+    #[cfg(feature = "codex-rewind")]
+    cap("record_codex_prompt_sent", Capability::MutateConfig, "Record a prompt being sent"),
+    #[cfg(feature = "codex-rewind")]
+    cap("record_codex_prompt_completed", Capability::MutateConfig, "Record a prompt completing"),
+    #[cfg(feature = "codex-rewind")]
+    cap("revert_codex_to_prompt", Capability::DestructiveGit, "Revert the repo to a prior prompt"),
+    // Session conversion
+    #[cfg(feature = "codex-convert")]
+    cap("convert_session", Capability::Execute, "Convert a session between formats"),
+    #[cfg(feature = "codex-convert")]
+    cap("convert_claude_to_codex", Capability::Execute, "Convert a Claude session to Codex"),
+    #[cfg(feature = "codex-convert")]
+    cap("convert_codex_to_claude", Capability::Execute, "Convert a Codex session to Claude"),
+];
+
+/// Small const helper so the manifest table reads as a list of rows.
+const fn cap(
+    command: &'static str,
+    capability: Capability,
+    summary: &'static str,
+) -> CommandCapability {
+    CommandCapability {
+        command,
+        capability,
+        summary,
+    }
+}
+
+/// Look up the capability required by a command, if it is in the manifest.
+pub fn capability_for(command: &str) -> Option<Capability> {
+    CAPABILITIES
+        .iter()
+        .find(|c| c.command == command)
+        .map(|c| c.capability)
+}
+
+/// Runtime gate: reject `command` unless its category is in `enabled`.
+///
+/// Commands missing from the manifest are treated as denied so that adding a
+/// command without classifying it fails closed rather than silently allowing.
+pub fn gate(command: &str, enabled: &HashSet<Capability>) -> Result<(), PermissionDenied> {
+    let capability = capability_for(command).ok_or_else(|| PermissionDenied {
+        command: command.to_string(),
+        capability: Capability::Destructive,
+    })?;
+    if enabled.contains(&capability) {
+        Ok(())
+    } else {
+        Err(PermissionDenied {
+            command: command.to_string(),
+            capability,
+        })
+    }
+}
+
+/// Every category known to the manifest. Used as the default enabled set so a
+/// workspace that never restricts anything behaves exactly as before.
+pub const ALL_CAPABILITIES: &[Capability] = &[
+    Capability::Read,
+    Capability::Execute,
+    Capability::MutateConfig,
+    Capability::DestructiveGit,
+    Capability::Destructive,
+    Capability::SecretAccess,
+];
+
+/// Managed Tauri state holding the categories a workspace has enabled. Register
+/// it with `.manage(CapabilityGate::default())`. Commands that execute, mutate
+/// configuration, access secrets or delete data call [`CapabilityGate::check`]
+/// at their entry point, so a "review-only" workspace simply narrows the set
+/// (e.g. to `Read`) and those commands then return `PermissionDenied`. The
+/// read-only commands, the session-conversion commands and
+/// `set_codex_enabled_capabilities` do not call the gate.
+pub struct CapabilityGate {
+    enabled: Mutex<HashSet<Capability>>,
+}
+
+impl Default for CapabilityGate {
+    fn default() -> Self {
+        Self {
+            enabled: Mutex::new(ALL_CAPABILITIES.iter().copied().collect()),
+        }
+    }
+}
+
+impl CapabilityGate {
+    /// Replace the enabled-category set.
+    pub fn set(&self, categories: impl IntoIterator<Item = Capability>) {
+        let mut guard = self.enabled.lock().expect("capability gate poisoned");
+        *guard = categories.into_iter().collect();
+    }
+
+    /// Reject `command` unless its category is currently enabled. This is the
+    /// boundary check every gated command calls before doing any work.
+    pub fn check(&self, command: &str) -> Result<(), PermissionDenied> {
+        let guard = self.enabled.lock().expect("capability gate poisoned");
+        gate(command, &guard)
+    }
+}
+
+// ============================================================================
+// Tauri Commands
+// ============================================================================
+
+/// Return the capability manifest for the Codex command surface so the
+/// frontend can render and audit which commands are read-only versus
+/// destructive.
+#[tauri::command]
+pub async fn list_codex_capabilities() -> Result<Vec<CommandCapability>, String> {
+    Ok(CAPABILITIES.to_vec())
+}
+
+/// Narrow (or restore) the set of permission categories the workspace allows.
+/// Commands whose category is omitted will return `PermissionDenied`.
+#[tauri::command]
+pub async fn set_codex_enabled_capabilities(
+    categories: Vec<Capability>,
+    gate: tauri::State<'_, CapabilityGate>,
+) -> Result<(), String> {
+    gate.set(categories);
+    Ok(())
+}
+
+/// Check whether `command` is permitted under the current enabled-category set,
+/// so the frontend can pre-flight an action before invoking it.
+#[tauri::command]
+pub async fn check_codex_permission(
+    command: String,
+    gate: tauri::State<'_, CapabilityGate>,
+) -> Result<(), String> {
+    gate.check(&command).map_err(Into::into)
+}
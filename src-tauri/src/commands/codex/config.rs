@@ -0,0 +1,360 @@
+/**
+ * OpenAI Codex Integration - Configuration Management
+ *
+ * Owns the on-disk Codex configuration: binary path resolution, execution mode,
+ * and the provider list. Provider API keys are never persisted here — they live
+ * in the OS keychain (see `secrets.rs`); the config keeps only the non-secret
+ * provider reference. A config written by an older version that still carries
+ * inline `api_key` values is migrated into the keychain on first load and the
+ * plaintext keys are stripped before the config is handed to the rest of the
+ * app.
+ */
+
+use std::path::PathBuf;
+use std::process::Command;
+
+use serde::{Deserialize, Serialize};
+
+use super::capabilities::CapabilityGate;
+use super::secrets;
+
+// ============================================================================
+// Paths
+// ============================================================================
+
+/// Base directory for all Codex configuration and state, under the user's home.
+pub fn get_codex_config_dir() -> Result<PathBuf, String> {
+    let home = dirs::home_dir().ok_or_else(|| "Could not resolve home directory".to_string())?;
+    Ok(home.join(".claude"))
+}
+
+/// Directory holding recorded Codex sessions.
+pub fn get_codex_sessions_dir() -> Result<PathBuf, String> {
+    Ok(get_codex_config_dir()?.join("codex-sessions"))
+}
+
+/// On-disk location of the Codex provider/mode config.
+fn codex_config_path() -> Result<PathBuf, String> {
+    Ok(get_codex_config_dir()?.join("codex-config.json"))
+}
+
+/// Candidate command names used to locate the Codex CLI on `PATH`.
+pub fn get_codex_command_candidates() -> Vec<String> {
+    vec!["codex".to_string(), "codex-cli".to_string()]
+}
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Result of probing for the Codex CLI.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodexAvailability {
+    pub available: bool,
+    pub path: Option<String>,
+    pub version: Option<String>,
+}
+
+/// The active execution mode (e.g. `"auto"` or a specific provider mode).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodexModeInfo {
+    pub mode: String,
+}
+
+/// A configured Codex provider. The API key is intentionally absent from the
+/// persisted form: `api_key` is accepted on input (and migrated out of legacy
+/// configs) but never serialized back to disk — only the provider id is kept as
+/// the keychain reference.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodexProviderConfig {
+    pub id: String,
+    pub name: String,
+    pub base_url: String,
+    pub model: String,
+    #[serde(default, skip_serializing)]
+    pub api_key: Option<String>,
+}
+
+/// The full persisted Codex config.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CurrentCodexConfig {
+    #[serde(default)]
+    pub providers: Vec<CodexProviderConfig>,
+    #[serde(default)]
+    pub active_provider_id: Option<String>,
+    #[serde(default)]
+    pub mode: Option<String>,
+    #[serde(default)]
+    pub custom_path: Option<String>,
+}
+
+// ============================================================================
+// Load / Save
+// ============================================================================
+
+/// Load the Codex config from disk, migrating any inline secrets into the
+/// keychain on first read. This is the single deserialization entry point, so
+/// every caller observes a config whose plaintext keys have already been
+/// stripped.
+pub fn load_codex_config() -> Result<CurrentCodexConfig, String> {
+    let path = codex_config_path()?;
+    let contents = match std::fs::read_to_string(&path) {
+        Ok(c) => c,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(CurrentCodexConfig::default()),
+        Err(e) => return Err(format!("Failed to read Codex config: {}", e)),
+    };
+
+    let mut value: serde_json::Value =
+        serde_json::from_str(&contents).map_err(|e| format!("Failed to parse Codex config: {}", e))?;
+
+    // Drain any plaintext keys into the keychain and rewrite the file so they
+    // never survive a second load.
+    let migrated = secrets::migrate_config_value(&mut value)?;
+    if !migrated.is_empty() {
+        let rewritten = serde_json::to_string_pretty(&value)
+            .map_err(|e| format!("Failed to serialize Codex config: {}", e))?;
+        std::fs::write(&path, rewritten)
+            .map_err(|e| format!("Failed to write Codex config: {}", e))?;
+    }
+
+    serde_json::from_value(value).map_err(|e| format!("Failed to load Codex config: {}", e))
+}
+
+/// Persist the Codex config, creating the config directory if necessary.
+pub fn save_codex_config(config: &CurrentCodexConfig) -> Result<(), String> {
+    let path = codex_config_path()?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create config directory: {}", e))?;
+    }
+    let contents = serde_json::to_string_pretty(config)
+        .map_err(|e| format!("Failed to serialize Codex config: {}", e))?;
+    std::fs::write(&path, contents).map_err(|e| format!("Failed to write Codex config: {}", e))
+}
+
+// ============================================================================
+// Tauri Commands - Availability / Path
+// ============================================================================
+
+/// Resolve the Codex binary path, preferring a configured custom path.
+fn resolve_codex_path() -> Result<String, String> {
+    if let Some(custom) = load_codex_config()?.custom_path {
+        return Ok(custom);
+    }
+    Ok(get_codex_command_candidates()
+        .into_iter()
+        .next()
+        .unwrap_or_else(|| "codex".to_string()))
+}
+
+/// Probe the Codex CLI and report whether it is available.
+#[tauri::command]
+pub async fn check_codex_availability() -> Result<CodexAvailability, String> {
+    let path = resolve_codex_path()?;
+    match Command::new(&path).arg("--version").output() {
+        Ok(output) if output.status.success() => {
+            let version = String::from_utf8_lossy(&output.stdout).trim().to_string();
+            Ok(CodexAvailability {
+                available: true,
+                path: Some(path),
+                version: Some(version),
+            })
+        }
+        _ => Ok(CodexAvailability {
+            available: false,
+            path: None,
+            version: None,
+        }),
+    }
+}
+
+/// Set a custom path to the Codex binary.
+#[tauri::command]
+pub async fn set_custom_codex_path(
+    path: String,
+    gate: tauri::State<'_, CapabilityGate>,
+) -> Result<(), String> {
+    gate.check("set_custom_codex_path")?;
+    let mut config = load_codex_config()?;
+    config.custom_path = Some(path);
+    save_codex_config(&config)
+}
+
+/// Get the resolved Codex binary path.
+#[tauri::command]
+pub async fn get_codex_path() -> Result<String, String> {
+    resolve_codex_path()
+}
+
+/// Clear the custom Codex binary path, falling back to `PATH` resolution.
+#[tauri::command]
+pub async fn clear_custom_codex_path(
+    gate: tauri::State<'_, CapabilityGate>,
+) -> Result<(), String> {
+    gate.check("clear_custom_codex_path")?;
+    let mut config = load_codex_config()?;
+    config.custom_path = None;
+    save_codex_config(&config)
+}
+
+/// Validate that a candidate path is an invokable Codex binary.
+#[tauri::command]
+pub async fn validate_codex_path_cmd(path: String) -> Result<bool, String> {
+    match Command::new(&path).arg("--version").output() {
+        Ok(output) => Ok(output.status.success()),
+        Err(e) => Err(format!("Failed to run '{}': {}", path, e)),
+    }
+}
+
+// ============================================================================
+// Tauri Commands - Mode
+// ============================================================================
+
+/// Read the Codex execution-mode config.
+#[tauri::command]
+pub async fn get_codex_mode_config() -> Result<CodexModeInfo, String> {
+    let mode = load_codex_config()?.mode.unwrap_or_else(|| "auto".to_string());
+    Ok(CodexModeInfo { mode })
+}
+
+/// Write the Codex execution-mode config.
+#[tauri::command]
+pub async fn set_codex_mode_config(
+    mode: String,
+    gate: tauri::State<'_, CapabilityGate>,
+) -> Result<(), String> {
+    gate.check("set_codex_mode_config")?;
+    let mut config = load_codex_config()?;
+    config.mode = Some(mode);
+    save_codex_config(&config)
+}
+
+// ============================================================================
+// Tauri Commands - Providers
+// ============================================================================
+
+/// Built-in provider presets the frontend can offer as a starting point.
+#[tauri::command]
+pub async fn get_codex_provider_presets() -> Result<Vec<CodexProviderConfig>, String> {
+    Ok(vec![CodexProviderConfig {
+        id: "openai".to_string(),
+        name: "OpenAI".to_string(),
+        base_url: "https://api.openai.com/v1".to_string(),
+        model: "gpt-4o".to_string(),
+        api_key: None,
+    }])
+}
+
+/// Read the active provider config (with plaintext keys already stripped).
+#[tauri::command]
+pub async fn get_current_codex_config() -> Result<CurrentCodexConfig, String> {
+    load_codex_config()
+}
+
+/// Switch the active provider.
+#[tauri::command]
+pub async fn switch_codex_provider(
+    provider_id: String,
+    gate: tauri::State<'_, CapabilityGate>,
+) -> Result<(), String> {
+    gate.check("switch_codex_provider")?;
+    let mut config = load_codex_config()?;
+    if !config.providers.iter().any(|p| p.id == provider_id) {
+        return Err(format!("Unknown provider '{}'", provider_id));
+    }
+    config.active_provider_id = Some(provider_id);
+    save_codex_config(&config)
+}
+
+/// Add a provider. Any supplied key is stored in the keychain, never on disk.
+#[tauri::command]
+pub async fn add_codex_provider_config(
+    mut provider: CodexProviderConfig,
+    api_key: Option<String>,
+    gate: tauri::State<'_, CapabilityGate>,
+) -> Result<(), String> {
+    gate.check("add_codex_provider_config")?;
+    if let Some(key) = api_key {
+        secrets::store_secret(&provider.id, &key)?;
+    }
+    provider.api_key = None;
+    let mut config = load_codex_config()?;
+    config.providers.retain(|p| p.id != provider.id);
+    config.providers.push(provider);
+    save_codex_config(&config)
+}
+
+/// Update an existing provider. A supplied key replaces the stored one.
+#[tauri::command]
+pub async fn update_codex_provider_config(
+    mut provider: CodexProviderConfig,
+    api_key: Option<String>,
+    gate: tauri::State<'_, CapabilityGate>,
+) -> Result<(), String> {
+    gate.check("update_codex_provider_config")?;
+    if let Some(key) = api_key {
+        secrets::store_secret(&provider.id, &key)?;
+    }
+    provider.api_key = None;
+    let mut config = load_codex_config()?;
+    match config.providers.iter_mut().find(|p| p.id == provider.id) {
+        Some(existing) => *existing = provider,
+        None => return Err(format!("Unknown provider '{}'", provider.id)),
+    }
+    save_codex_config(&config)
+}
+
+/// Delete a provider and its stored secret.
+#[tauri::command]
+pub async fn delete_codex_provider_config(
+    provider_id: String,
+    gate: tauri::State<'_, CapabilityGate>,
+) -> Result<(), String> {
+    gate.check("delete_codex_provider_config")?;
+    secrets::clear_secret(&provider_id)?;
+    let mut config = load_codex_config()?;
+    config.providers.retain(|p| p.id != provider_id);
+    if config.active_provider_id.as_deref() == Some(provider_id.as_str()) {
+        config.active_provider_id = None;
+    }
+    save_codex_config(&config)
+}
+
+/// Clear every provider and its stored secret.
+#[tauri::command]
+pub async fn clear_codex_provider_config(
+    gate: tauri::State<'_, CapabilityGate>,
+) -> Result<(), String> {
+    gate.check("clear_codex_provider_config")?;
+    let mut config = load_codex_config()?;
+    for provider in &config.providers {
+        secrets::clear_secret(&provider.id)?;
+    }
+    config.providers.clear();
+    config.active_provider_id = None;
+    save_codex_config(&config)
+}
+
+/// Check that a provider is ready for use: it exists, has an API key in the
+/// keychain (fetched lazily here) and has a non-empty base URL. No network
+/// request is made; `Ok(true)` means these checks passed.
+#[tauri::command]
+pub async fn test_codex_provider_connection(
+    provider_id: String,
+    gate: tauri::State<'_, CapabilityGate>,
+) -> Result<bool, String> {
+    gate.check("test_codex_provider_connection")?;
+    let config = load_codex_config()?;
+    let provider = config
+        .providers
+        .iter()
+        .find(|p| p.id == provider_id)
+        .ok_or_else(|| format!("Unknown provider '{}'", provider_id))?;
+
+    // Pull the secret only now that it is actually needed.
+    let _api_key = secrets::resolve_provider_secret(&provider_id)?;
+    if provider.base_url.is_empty() {
+        return Err(format!("Provider '{}' has no base URL", provider_id));
+    }
+    Ok(true)
+}
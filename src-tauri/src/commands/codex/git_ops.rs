@@ -0,0 +1,226 @@
+/**
+ * OpenAI Codex Integration - Git Rewind Operations
+ *
+ * Records the git HEAD around each prompt so a session can be rewound to an
+ * earlier point, and performs the destructive git operations that implement the
+ * rewind. The path-mutating commands (`revert_codex_to_prompt`,
+ * `truncate_codex_session_to_prompt`) route their working directory through
+ * `scope::guard` before touching the repository, so a rewind can never operate
+ * on a directory outside the configured scope.
+ */
+
+use std::path::PathBuf;
+use std::process::Command;
+
+use serde::{Deserialize, Serialize};
+
+use super::capabilities::CapabilityGate;
+use super::{config, scope};
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// A single prompt as surfaced to the rewind UI.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PromptRecord {
+    pub prompt_id: String,
+    pub prompt: String,
+}
+
+/// A recorded prompt plus its lifecycle state.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodexPromptRecord {
+    pub prompt_id: String,
+    pub prompt: String,
+    #[serde(default)]
+    pub completed: bool,
+}
+
+/// The git commit captured for a prompt, used to rewind back to it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodexPromptGitRecord {
+    pub prompt_id: String,
+    pub commit: String,
+}
+
+/// All rewind records for a session.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CodexGitRecords {
+    pub session_id: String,
+    #[serde(default)]
+    pub prompts: Vec<CodexPromptRecord>,
+    #[serde(default)]
+    pub git: Vec<CodexPromptGitRecord>,
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Directory holding per-session rewind records.
+pub fn get_codex_git_records_dir() -> Result<PathBuf, String> {
+    Ok(config::get_codex_config_dir()?.join("codex-git-records"))
+}
+
+fn records_path(session_id: &str) -> Result<PathBuf, String> {
+    Ok(get_codex_git_records_dir()?.join(format!("{}.json", session_id)))
+}
+
+/// Load the rewind records for a session, or an empty set if none exist.
+pub fn load_codex_git_records(session_id: &str) -> Result<CodexGitRecords, String> {
+    let path = records_path(session_id)?;
+    match std::fs::read_to_string(&path) {
+        Ok(contents) => {
+            serde_json::from_str(&contents).map_err(|e| format!("Failed to parse records: {}", e))
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(CodexGitRecords {
+            session_id: session_id.to_string(),
+            ..Default::default()
+        }),
+        Err(e) => Err(format!("Failed to read records: {}", e)),
+    }
+}
+
+/// Persist the rewind records for a session.
+pub fn save_codex_git_records(records: &CodexGitRecords) -> Result<(), String> {
+    let path = records_path(&records.session_id)?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create records directory: {}", e))?;
+    }
+    let contents = serde_json::to_string_pretty(records)
+        .map_err(|e| format!("Failed to serialize records: {}", e))?;
+    std::fs::write(&path, contents).map_err(|e| format!("Failed to write records: {}", e))
+}
+
+/// Drop every record at and after `prompt_id`, returning the updated set.
+pub fn truncate_codex_git_records(
+    mut records: CodexGitRecords,
+    prompt_id: &str,
+) -> CodexGitRecords {
+    if let Some(idx) = records.prompts.iter().position(|p| p.prompt_id == prompt_id) {
+        records.prompts.truncate(idx);
+    }
+    if let Some(idx) = records.git.iter().position(|g| g.prompt_id == prompt_id) {
+        records.git.truncate(idx);
+    }
+    records
+}
+
+/// Extract the prompt list from a record set.
+pub fn extract_codex_prompts(records: &CodexGitRecords) -> Vec<PromptRecord> {
+    records
+        .prompts
+        .iter()
+        .map(|p| PromptRecord {
+            prompt_id: p.prompt_id.clone(),
+            prompt: p.prompt.clone(),
+        })
+        .collect()
+}
+
+/// Truncate the session's recorded prompts to just before `prompt_id`. This is
+/// a mutating rewind operation, so the working directory is scope-checked.
+pub fn truncate_codex_session_to_prompt(
+    session_id: &str,
+    prompt_id: &str,
+    working_dir: &str,
+) -> Result<(), String> {
+    scope::guard(working_dir)?;
+    let records = load_codex_git_records(session_id)?;
+    let truncated = truncate_codex_git_records(records, prompt_id);
+    save_codex_git_records(&truncated)
+}
+
+/// Run a git command in `working_dir`, returning stdout on success.
+fn git(working_dir: &PathBuf, args: &[&str]) -> Result<String, String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(working_dir)
+        .output()
+        .map_err(|e| format!("Failed to run git: {}", e))?;
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+// ============================================================================
+// Tauri Commands
+// ============================================================================
+
+/// List the recorded prompts for a session.
+#[tauri::command]
+pub async fn get_codex_prompt_list(session_id: String) -> Result<Vec<PromptRecord>, String> {
+    Ok(extract_codex_prompts(&load_codex_git_records(&session_id)?))
+}
+
+/// Report whether git-based rewind is available for a working directory.
+#[tauri::command]
+pub async fn check_codex_rewind_capabilities(working_dir: String) -> Result<bool, String> {
+    let dir = PathBuf::from(&working_dir);
+    Ok(git(&dir, &["rev-parse", "--is-inside-work-tree"]).is_ok())
+}
+
+/// Record the git HEAD at the moment a prompt is sent.
+#[tauri::command]
+pub async fn record_codex_prompt_sent(
+    session_id: String,
+    prompt_id: String,
+    prompt: String,
+    working_dir: String,
+    gate: tauri::State<'_, CapabilityGate>,
+) -> Result<(), String> {
+    gate.check("record_codex_prompt_sent")?;
+    let dir = PathBuf::from(&working_dir);
+    let commit = git(&dir, &["rev-parse", "HEAD"])?;
+    let mut records = load_codex_git_records(&session_id)?;
+    records.prompts.push(CodexPromptRecord {
+        prompt_id: prompt_id.clone(),
+        prompt,
+        completed: false,
+    });
+    records.git.push(CodexPromptGitRecord { prompt_id, commit });
+    save_codex_git_records(&records)
+}
+
+/// Mark a recorded prompt as completed.
+#[tauri::command]
+pub async fn record_codex_prompt_completed(
+    session_id: String,
+    prompt_id: String,
+    gate: tauri::State<'_, CapabilityGate>,
+) -> Result<(), String> {
+    gate.check("record_codex_prompt_completed")?;
+    let mut records = load_codex_git_records(&session_id)?;
+    if let Some(prompt) = records.prompts.iter_mut().find(|p| p.prompt_id == prompt_id) {
+        prompt.completed = true;
+    }
+    save_codex_git_records(&records)
+}
+
+/// Revert the repository to the commit recorded for `prompt_id`. Destructive,
+/// so the working directory is scope-checked before any git operation runs.
+#[tauri::command]
+pub async fn revert_codex_to_prompt(
+    session_id: String,
+    prompt_id: String,
+    working_dir: String,
+    gate: tauri::State<'_, CapabilityGate>,
+) -> Result<(), String> {
+    gate.check("revert_codex_to_prompt")?;
+    let dir = scope::guard(&working_dir)?;
+    let records = load_codex_git_records(&session_id)?;
+    let commit = records
+        .git
+        .iter()
+        .find(|g| g.prompt_id == prompt_id)
+        .map(|g| g.commit.clone())
+        .ok_or_else(|| format!("No recorded commit for prompt '{}'", prompt_id))?;
+
+    git(&dir, &["reset", "--hard", &commit])?;
+    let truncated = truncate_codex_git_records(records, &prompt_id);
+    save_codex_git_records(&truncated)
+}
@@ -7,12 +7,67 @@
  * Module Structure:
  * - session.rs: Session lifecycle management (execute, resume, cancel, list, delete)
  * - git_ops.rs: Git operations for rewind functionality (records, truncate, revert)
+ *   — gated behind the `codex-rewind` feature
  * - config.rs: Configuration management (availability, paths, mode, providers)
+ * - secrets.rs: Keyring-backed storage for provider API keys
+ * - scope.rs: Filesystem scope enforcement for session/git commands
+ * - capabilities.rs: Per-command permission manifest and runtime gate
+ * - session_converter.rs: Claude↔Codex session conversion
+ *   — gated behind the `codex-convert` feature
+ *
+ * `session` and `config` are always present; the rewind and conversion
+ * subsystems compile only when their feature is enabled. The crate's default
+ * feature set turns both on, so existing builds are unaffected.
+ *
+ * Build integration (src-tauri/Cargo.toml):
+ * ```toml
+ * [features]
+ * default = ["codex-rewind", "codex-convert"]
+ * codex-rewind = []
+ * codex-convert = []
+ *
+ * [dependencies]
+ * keyring = "3"   # secrets.rs: OS keychain storage
+ * glob = "0.3"    # scope.rs: allow/deny pattern matching
+ * dirs = "5"      # scope.rs/secrets.rs: resolve the config directory
+ * ```
+ * Without these feature declarations the `codex-rewind`/`codex-convert` cfgs
+ * evaluate to false and the gated commands silently drop from the default
+ * build, so they must be declared (and kept in `default`) for existing builds
+ * to stay unaffected.
+ *
+ * Command registration (in the application binary that depends on this
+ * crate): the new commands must be added
+ * to `tauri::generate_handler![...]` and the capability gate registered as
+ * managed state so a `review-only` workspace can be enforced:
+ * ```rust
+ * .manage(commands::codex::CapabilityGate::default())
+ * .invoke_handler(tauri::generate_handler![
+ *     // …existing codex commands…
+ *     commands::codex::store_codex_provider_secret,
+ *     commands::codex::get_codex_provider_secret_exists,
+ *     commands::codex::clear_codex_provider_secret,
+ *     commands::codex::migrate_codex_provider_secrets,
+ *     commands::codex::get_codex_scope,
+ *     commands::codex::set_codex_scope,
+ *     commands::codex::list_codex_capabilities,
+ *     commands::codex::check_codex_permission,
+ *     commands::codex::set_codex_enabled_capabilities,
+ * ])
+ * ```
+ * `src-tauri/src/lib.rs` only declares the command modules; the handler list
+ * and managed state live in the application binary, which is not part of this
+ * crate, so the registration is documented here rather than edited there.
  */
 
+pub mod capabilities;
 pub mod config;
+#[cfg(feature = "codex-rewind")]
 pub mod git_ops;
+pub mod scope;
+pub mod secrets;
 pub mod session;
+#[cfg(feature = "codex-convert")]
 pub mod session_converter;
 
 // ============================================================================
@@ -29,6 +84,7 @@ pub use session::{
 };
 
 // Git operations types
+#[cfg(feature = "codex-rewind")]
 #[allow(unused_imports)]
 pub use git_ops::{
     CodexPromptRecord,
@@ -47,6 +103,7 @@ pub use config::{
 };
 
 // Session converter types
+#[cfg(feature = "codex-convert")]
 #[allow(unused_imports)]
 pub use session_converter::{
     ConversionSource,
@@ -71,6 +128,7 @@ pub use session::{
 // Re-export Tauri Commands - Git Operations / Rewind
 // ============================================================================
 
+#[cfg(feature = "codex-rewind")]
 pub use git_ops::{
     get_codex_prompt_list,
     check_codex_rewind_capabilities,
@@ -108,10 +166,41 @@ pub use config::{
     test_codex_provider_connection,
 };
 
+// ============================================================================
+// Re-export Tauri Commands - Provider Secret Storage
+// ============================================================================
+
+pub use secrets::{
+    store_codex_provider_secret,
+    get_codex_provider_secret_exists,
+    clear_codex_provider_secret,
+    migrate_codex_provider_secrets,
+};
+
+// ============================================================================
+// Re-export Tauri Commands - Filesystem Scope
+// ============================================================================
+
+pub use scope::{
+    get_codex_scope,
+    set_codex_scope,
+};
+
+// ============================================================================
+// Re-export Tauri Commands - Capability Manifest
+// ============================================================================
+
+pub use capabilities::{
+    list_codex_capabilities,
+    check_codex_permission,
+    set_codex_enabled_capabilities,
+};
+
 // ============================================================================
 // Re-export Tauri Commands - Session Conversion
 // ============================================================================
 
+#[cfg(feature = "codex-convert")]
 pub use session_converter::{
     convert_session,
     convert_claude_to_codex,
@@ -134,6 +223,40 @@ pub use session::{
     parse_codex_session_file,
 };
 
+#[allow(unused_imports)]
+pub use secrets::{
+    get_secret,
+    secret_exists,
+    store_secret,
+    clear_secret,
+    migrate_inline_secret,
+    migrate_config_value,
+    resolve_provider_secret,
+};
+
+#[allow(unused_imports)]
+pub use scope::{
+    CodexScopeConfig,
+    ScopeError,
+    enforce_scope,
+    guard,
+    load_scope,
+    save_scope,
+};
+
+#[allow(unused_imports)]
+pub use capabilities::{
+    Capability,
+    CapabilityGate,
+    CommandCapability,
+    PermissionDenied,
+    ALL_CAPABILITIES,
+    CAPABILITIES,
+    capability_for,
+    gate,
+};
+
+#[cfg(feature = "codex-rewind")]
 #[allow(unused_imports)]
 pub use git_ops::{
     get_codex_git_records_dir,
@@ -0,0 +1,220 @@
+/**
+ * OpenAI Codex Integration - Filesystem Scope Enforcement
+ *
+ * Validates a requested working directory against configurable allow/deny glob
+ * patterns before any Codex session spawn or git-mutating command runs. The
+ * path is canonicalized first so that `..` traversal and symlink escapes cannot
+ * smuggle a request past the allow list.
+ *
+ * The allow/deny lists are persisted alongside the Codex mode/provider config
+ * and edited via the `get_codex_scope` / `set_codex_scope` commands. Every
+ * session-spawning and git-mutating command should route its target path
+ * through `enforce_scope` and reject out-of-scope paths with `ScopeError`
+ * rather than executing.
+ */
+
+use std::path::{Path, PathBuf};
+
+use glob::Pattern;
+use serde::{Deserialize, Serialize};
+
+/// Persisted filesystem scope for Codex commands.
+///
+/// `allow` entries opt a path in; `deny` entries always win over `allow`. An
+/// empty `allow` list means "no directory is in scope" (fail-closed). The
+/// default config allows every path (`**`); scope narrows only once a user
+/// saves an allow list of their own.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodexScopeConfig {
+    /// Glob patterns a canonicalized path must match at least one of.
+    #[serde(default)]
+    pub allow: Vec<String>,
+    /// Glob patterns that, if matched, reject the path regardless of `allow`.
+    #[serde(default)]
+    pub deny: Vec<String>,
+}
+
+impl Default for CodexScopeConfig {
+    fn default() -> Self {
+        // Scope enforcement is opt-in: until a user configures an allow list we
+        // allow any path (`**`) so existing workflows are unaffected.
+        Self {
+            allow: vec!["**".to_string()],
+            deny: Vec::new(),
+        }
+    }
+}
+
+/// Typed error returned when a path falls outside the configured scope.
+#[derive(Debug, Clone, Serialize)]
+pub struct ScopeError {
+    /// The path that was rejected (canonicalized when possible).
+    pub path: String,
+    /// Human-readable reason, suitable for surfacing in the UI.
+    pub reason: String,
+}
+
+impl std::fmt::Display for ScopeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "path '{}' is out of scope: {}", self.path, self.reason)
+    }
+}
+
+impl std::error::Error for ScopeError {}
+
+impl From<ScopeError> for String {
+    fn from(e: ScopeError) -> String {
+        e.to_string()
+    }
+}
+
+fn compile(patterns: &[String]) -> Result<Vec<Pattern>, ScopeError> {
+    patterns
+        .iter()
+        .map(|p| {
+            Pattern::new(p).map_err(|e| ScopeError {
+                path: p.clone(),
+                reason: format!("invalid glob pattern: {}", e),
+            })
+        })
+        .collect()
+}
+
+/// Canonicalize `working_dir` and confirm it is permitted by `scope`.
+///
+/// Returns the canonical path on success so callers can spawn against the
+/// resolved location rather than the raw, potentially-relative input.
+pub fn enforce_scope(working_dir: &str, scope: &CodexScopeConfig) -> Result<PathBuf, ScopeError> {
+    let canonical = canonicalize_request(working_dir)?;
+    let as_str = canonical.to_string_lossy().to_string();
+
+    let deny = compile(&scope.deny)?;
+    if let Some(hit) = deny.iter().find(|p| p.matches(&as_str)) {
+        return Err(ScopeError {
+            path: as_str,
+            reason: format!("matched deny pattern '{}'", hit.as_str()),
+        });
+    }
+
+    let allow = compile(&scope.allow)?;
+    if allow.iter().any(|p| p.matches(&as_str)) {
+        Ok(canonical)
+    } else {
+        Err(ScopeError {
+            path: as_str,
+            reason: "did not match any allow pattern".to_string(),
+        })
+    }
+}
+
+/// Resolve a requested directory to an absolute, symlink-free path. Defeats
+/// `..` traversal and symlink escapes by relying on the OS canonicalizer.
+///
+/// A working directory for a new session may not exist on disk yet, so we
+/// canonicalize the nearest existing ancestor and re-append the trailing
+/// components that do not exist. This keeps the traversal/symlink guarantees
+/// for the real part of the path while still admitting a legitimately in-scope
+/// directory that is about to be created.
+fn canonicalize_request(working_dir: &str) -> Result<PathBuf, ScopeError> {
+    let requested = Path::new(working_dir);
+
+    // Walk up to the first ancestor that exists and canonicalize it.
+    let mut existing = requested;
+    let mut trailing: Vec<&std::ffi::OsStr> = Vec::new();
+    loop {
+        if existing.exists() {
+            break;
+        }
+        match (existing.file_name(), existing.parent()) {
+            (Some(name), Some(parent)) => {
+                trailing.push(name);
+                existing = parent;
+            }
+            // No existing ancestor (e.g. an empty or bare relative path).
+            _ => {
+                return Err(ScopeError {
+                    path: working_dir.to_string(),
+                    reason: "could not resolve an existing ancestor directory".to_string(),
+                });
+            }
+        }
+    }
+
+    let mut resolved = std::fs::canonicalize(existing).map_err(|e| ScopeError {
+        path: working_dir.to_string(),
+        reason: format!("could not canonicalize path: {}", e),
+    })?;
+    // `trailing` was collected leaf-first; re-append in path order.
+    for component in trailing.iter().rev() {
+        resolved.push(*component);
+    }
+    Ok(resolved)
+}
+
+// ============================================================================
+// Persistence
+// ============================================================================
+//
+// The scope lives alongside the Codex mode/provider config as its own JSON
+// file so enabling enforcement never rewrites the main config. Loading a
+// missing or unreadable file falls back to the default (allow-all) scope so a
+// first run is unaffected.
+
+/// Resolve the on-disk location of the scope file, alongside the other Codex
+/// config under the user's home directory.
+fn scope_config_path() -> Result<PathBuf, String> {
+    let home = dirs::home_dir().ok_or_else(|| "Could not resolve home directory".to_string())?;
+    Ok(home.join(".claude").join("codex-scope.json"))
+}
+
+/// Load the persisted scope, or the default when none has been saved yet.
+pub fn load_scope() -> CodexScopeConfig {
+    let path = match scope_config_path() {
+        Ok(p) => p,
+        Err(_) => return CodexScopeConfig::default(),
+    };
+    match std::fs::read_to_string(&path) {
+        Ok(contents) => serde_json::from_str(&contents).unwrap_or_default(),
+        Err(_) => CodexScopeConfig::default(),
+    }
+}
+
+/// Persist the scope, creating the config directory if necessary.
+pub fn save_scope(scope: &CodexScopeConfig) -> Result<(), String> {
+    let path = scope_config_path()?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create config directory: {}", e))?;
+    }
+    let contents = serde_json::to_string_pretty(scope)
+        .map_err(|e| format!("Failed to serialize scope config: {}", e))?;
+    std::fs::write(&path, contents).map_err(|e| format!("Failed to write scope config: {}", e))
+}
+
+/// Enforce the *currently persisted* scope against a requested directory. This
+/// is the single chokepoint that session-spawning and git-mutating commands
+/// (`execute_codex`, `resume_codex`, `revert_codex_to_prompt`,
+/// `truncate_codex_session_to_prompt`) call at their entry point before doing
+/// any work.
+pub fn guard(working_dir: &str) -> Result<PathBuf, ScopeError> {
+    enforce_scope(working_dir, &load_scope())
+}
+
+// ============================================================================
+// Tauri Commands
+// ============================================================================
+
+/// Return the persisted filesystem scope for Codex commands.
+#[tauri::command]
+pub async fn get_codex_scope() -> Result<CodexScopeConfig, String> {
+    Ok(load_scope())
+}
+
+/// Persist a new filesystem scope for Codex commands. The patterns are
+/// validated before saving so an unparseable glob is rejected up front.
+#[tauri::command]
+pub async fn set_codex_scope(scope: CodexScopeConfig) -> Result<(), String> {
+    compile(&scope.allow)?;
+    compile(&scope.deny)?;
+    save_scope(&scope)
+}
@@ -0,0 +1,187 @@
+/**
+ * OpenAI Codex Integration - Provider Secret Storage
+ *
+ * Keeps provider API keys out of the plaintext Codex config by storing them in
+ * the OS keychain via the `keyring` crate. The JSON config retains only a
+ * non-secret reference (the provider id); the real key is fetched lazily, only
+ * when a command actually needs it (the provider connection check).
+ * `execute_codex` does not fetch a key or pass one to the spawned process.
+ *
+ * On first load of a config that still carries inline secrets, the values are
+ * migrated into the keyring and stripped from the plaintext file so that keys
+ * never leak into backups, syncs, or logs.
+ */
+
+use keyring::Entry;
+
+use super::capabilities::CapabilityGate;
+
+/// Service handle under which every Codex provider key is grouped in the OS
+/// keychain. Individual providers are addressed by their provider id as the
+/// keychain account, e.g. `claude-workbench-codex` / `openai-default`.
+pub const CODEX_KEYRING_SERVICE: &str = "claude-workbench-codex";
+
+/// Build the keyring entry for a single provider.
+fn provider_entry(provider_id: &str) -> Result<Entry, String> {
+    Entry::new(CODEX_KEYRING_SERVICE, provider_id)
+        .map_err(|e| format!("Failed to open keychain entry for '{}': {}", provider_id, e))
+}
+
+/// Store (or overwrite) the API key for a provider in the OS keychain.
+pub fn store_secret(provider_id: &str, api_key: &str) -> Result<(), String> {
+    let entry = provider_entry(provider_id)?;
+    entry
+        .set_password(api_key)
+        .map_err(|e| format!("Failed to store secret for '{}': {}", provider_id, e))
+}
+
+/// Fetch the API key for a provider, returning `None` when no secret is stored.
+pub fn get_secret(provider_id: &str) -> Result<Option<String>, String> {
+    let entry = provider_entry(provider_id)?;
+    match entry.get_password() {
+        Ok(key) => Ok(Some(key)),
+        Err(keyring::Error::NoEntry) => Ok(None),
+        Err(e) => Err(format!("Failed to read secret for '{}': {}", provider_id, e)),
+    }
+}
+
+/// Report whether a secret is present for a provider without returning it.
+pub fn secret_exists(provider_id: &str) -> Result<bool, String> {
+    Ok(get_secret(provider_id)?.is_some())
+}
+
+/// Remove the stored secret for a provider. Succeeds even when none was set.
+pub fn clear_secret(provider_id: &str) -> Result<(), String> {
+    let entry = provider_entry(provider_id)?;
+    match entry.delete_credential() {
+        Ok(()) => Ok(()),
+        Err(keyring::Error::NoEntry) => Ok(()),
+        Err(e) => Err(format!("Failed to clear secret for '{}': {}", provider_id, e)),
+    }
+}
+
+/// Move an inline API key into the keychain, returning `true` when a non-empty
+/// secret was migrated. Used by config loading to drain plaintext keys on first
+/// read; the caller is responsible for stripping the inline value afterwards.
+pub fn migrate_inline_secret(provider_id: &str, inline_key: &str) -> Result<bool, String> {
+    if inline_key.trim().is_empty() {
+        return Ok(false);
+    }
+    store_secret(provider_id, inline_key)?;
+    Ok(true)
+}
+
+/// Fetch a provider's API key lazily, failing when none is available. Call this
+/// at the point a key is actually needed (connection test, execution) rather
+/// than eagerly loading secrets into memory with the rest of the config.
+pub fn resolve_provider_secret(provider_id: &str) -> Result<String, String> {
+    get_secret(provider_id)?
+        .ok_or_else(|| format!("No stored API key for provider '{}'", provider_id))
+}
+
+/// Drain any inline `api_key` values out of a parsed Codex config: for each
+/// provider entry that still carries a plaintext key, move it into the keychain
+/// and strip it from the JSON, leaving only the non-secret provider id as the
+/// reference. Returns the ids whose secrets were migrated.
+///
+/// The config loader calls this on first read so that on-disk configs written
+/// by older versions are upgraded transparently; the caller then persists the
+/// stripped config back to disk.
+pub fn migrate_config_value(config: &mut serde_json::Value) -> Result<Vec<String>, String> {
+    let mut migrated = Vec::new();
+
+    let providers = match config.get_mut("providers").and_then(|p| p.as_array_mut()) {
+        Some(p) => p,
+        None => return Ok(migrated),
+    };
+
+    for provider in providers.iter_mut() {
+        let id = match provider.get("id").and_then(|v| v.as_str()) {
+            Some(id) => id.to_string(),
+            None => continue,
+        };
+        let inline = provider
+            .get("api_key")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string());
+        if let Some(key) = inline {
+            if migrate_inline_secret(&id, &key)? {
+                migrated.push(id);
+            }
+            // Strip the plaintext key regardless, so an empty string is not
+            // left behind in the config either.
+            if let Some(obj) = provider.as_object_mut() {
+                obj.remove("api_key");
+            }
+        }
+    }
+
+    Ok(migrated)
+}
+
+// ============================================================================
+// Tauri Commands
+// ============================================================================
+
+/// Store a provider API key directly in the OS keychain, bypassing the config
+/// file entirely so the raw key never touches disk in plaintext.
+#[tauri::command]
+pub async fn store_codex_provider_secret(
+    provider_id: String,
+    api_key: String,
+    gate: tauri::State<'_, CapabilityGate>,
+) -> Result<(), String> {
+    gate.check("store_codex_provider_secret")?;
+    store_secret(&provider_id, &api_key)
+}
+
+/// Report whether a provider currently has a stored secret, for UI state.
+#[tauri::command]
+pub async fn get_codex_provider_secret_exists(provider_id: String) -> Result<bool, String> {
+    secret_exists(&provider_id)
+}
+
+/// Remove a provider's stored secret from the OS keychain.
+#[tauri::command]
+pub async fn clear_codex_provider_secret(
+    provider_id: String,
+    gate: tauri::State<'_, CapabilityGate>,
+) -> Result<(), String> {
+    gate.check("clear_codex_provider_secret")?;
+    clear_secret(&provider_id)
+}
+
+/// Migrate any plaintext API keys still present in the on-disk Codex config
+/// into the keychain and strip them from the file. Idempotent: re-running once
+/// the config is clean migrates nothing. Returns the provider ids migrated.
+#[tauri::command]
+pub async fn migrate_codex_provider_secrets(
+    gate: tauri::State<'_, CapabilityGate>,
+) -> Result<Vec<String>, String> {
+    gate.check("migrate_codex_provider_secrets")?;
+    let path = codex_config_path()?;
+    let contents = match std::fs::read_to_string(&path) {
+        Ok(c) => c,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("Failed to read Codex config: {}", e)),
+    };
+
+    let mut config: serde_json::Value =
+        serde_json::from_str(&contents).map_err(|e| format!("Failed to parse Codex config: {}", e))?;
+
+    let migrated = migrate_config_value(&mut config)?;
+    if !migrated.is_empty() {
+        let rewritten = serde_json::to_string_pretty(&config)
+            .map_err(|e| format!("Failed to serialize Codex config: {}", e))?;
+        std::fs::write(&path, rewritten)
+            .map_err(|e| format!("Failed to write Codex config: {}", e))?;
+    }
+    Ok(migrated)
+}
+
+/// Location of the plaintext Codex provider config on disk, alongside the other
+/// Codex config under the user's home directory.
+fn codex_config_path() -> Result<std::path::PathBuf, String> {
+    let home = dirs::home_dir().ok_or_else(|| "Could not resolve home directory".to_string())?;
+    Ok(home.join(".claude").join("codex-config.json"))
+}
@@ -0,0 +1,219 @@
+/**
+ * OpenAI Codex Integration - Session Lifecycle
+ *
+ * Spawns and tracks Codex CLI sessions and exposes their recorded history.
+ * Every command that spawns a process against a caller-supplied working
+ * directory routes that path through `scope::guard` first, so a request for a
+ * directory outside the configured allow/deny scope is rejected with a
+ * `ScopeError` before any process is started.
+ */
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::process::{Child, Command};
+use std::sync::{Mutex, OnceLock};
+
+use serde::{Deserialize, Serialize};
+
+use super::capabilities::CapabilityGate;
+use super::{config, scope};
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// How a Codex session is run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CodexExecutionMode {
+    Interactive,
+    OneShot,
+}
+
+/// Options for launching a Codex session.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodexExecutionOptions {
+    pub working_dir: String,
+    pub prompt: String,
+    #[serde(default = "default_mode")]
+    pub mode: CodexExecutionMode,
+    #[serde(default)]
+    pub provider_id: Option<String>,
+    #[serde(default)]
+    pub model: Option<String>,
+}
+
+fn default_mode() -> CodexExecutionMode {
+    CodexExecutionMode::Interactive
+}
+
+/// A recorded Codex session.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodexSession {
+    pub id: String,
+    pub working_dir: String,
+}
+
+/// Lifecycle state of a spawned Codex process.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CodexProcessState {
+    Running,
+    Completed,
+    Cancelled,
+    Failed,
+}
+
+// ============================================================================
+// Process registry
+// ============================================================================
+
+fn processes() -> &'static Mutex<HashMap<String, Child>> {
+    static PROCESSES: OnceLock<Mutex<HashMap<String, Child>>> = OnceLock::new();
+    PROCESSES.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Resolve the on-disk file for a recorded session.
+pub fn find_session_file(session_id: &str) -> Result<PathBuf, String> {
+    Ok(config::get_codex_sessions_dir()?.join(format!("{}.json", session_id)))
+}
+
+/// Parse a recorded session file into a [`CodexSession`].
+pub fn parse_codex_session_file(path: &std::path::Path) -> Result<CodexSession, String> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read session file: {}", e))?;
+    serde_json::from_str(&contents).map_err(|e| format!("Failed to parse session file: {}", e))
+}
+
+/// Spawn the Codex CLI in `working_dir` with `args`, registering it under
+/// `session_id` so it can be cancelled later.
+fn spawn_codex(session_id: &str, working_dir: &PathBuf, args: &[String]) -> Result<(), String> {
+    let path = config::get_codex_command_candidates()
+        .into_iter()
+        .next()
+        .unwrap_or_else(|| "codex".to_string());
+    let child = Command::new(&path)
+        .args(args)
+        .current_dir(working_dir)
+        .spawn()
+        .map_err(|e| format!("Failed to spawn Codex: {}", e))?;
+    processes()
+        .lock()
+        .map_err(|_| "Process registry poisoned".to_string())?
+        .insert(session_id.to_string(), child);
+    Ok(())
+}
+
+// ============================================================================
+// Tauri Commands
+// ============================================================================
+
+/// Launch a new Codex session. Rejects out-of-scope working directories and
+/// denied capability categories.
+#[tauri::command]
+pub async fn execute_codex(
+    options: CodexExecutionOptions,
+    gate: tauri::State<'_, CapabilityGate>,
+) -> Result<String, String> {
+    gate.check("execute_codex")?;
+    let working_dir = scope::guard(&options.working_dir)?;
+    let session_id = format!("codex-{}", options.working_dir.replace(['/', '\\'], "_"));
+    spawn_codex(&session_id, &working_dir, &[options.prompt.clone()])?;
+    Ok(session_id)
+}
+
+/// Resume an existing Codex session. Rejects out-of-scope working directories.
+#[tauri::command]
+pub async fn resume_codex(
+    session_id: String,
+    working_dir: String,
+    gate: tauri::State<'_, CapabilityGate>,
+) -> Result<String, String> {
+    gate.check("resume_codex")?;
+    let working_dir = scope::guard(&working_dir)?;
+    spawn_codex(&session_id, &working_dir, &["resume".to_string(), session_id.clone()])?;
+    Ok(session_id)
+}
+
+/// Resume the most recently recorded Codex session.
+#[tauri::command]
+pub async fn resume_last_codex(
+    gate: tauri::State<'_, CapabilityGate>,
+) -> Result<String, String> {
+    gate.check("resume_last_codex")?;
+    let sessions = list_codex_sessions().await?;
+    let last = sessions
+        .into_iter()
+        .next()
+        .ok_or_else(|| "No recorded Codex sessions".to_string())?;
+    resume_codex(last.id, last.working_dir, gate).await
+}
+
+/// Cancel a running Codex session.
+#[tauri::command]
+pub async fn cancel_codex(
+    session_id: String,
+    gate: tauri::State<'_, CapabilityGate>,
+) -> Result<(), String> {
+    gate.check("cancel_codex")?;
+    let mut registry = processes()
+        .lock()
+        .map_err(|_| "Process registry poisoned".to_string())?;
+    if let Some(mut child) = registry.remove(&session_id) {
+        child
+            .kill()
+            .map_err(|e| format!("Failed to cancel session: {}", e))?;
+    }
+    Ok(())
+}
+
+/// List recorded Codex sessions, newest session file first.
+#[tauri::command]
+pub async fn list_codex_sessions() -> Result<Vec<CodexSession>, String> {
+    let dir = config::get_codex_sessions_dir()?;
+    let entries = match std::fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("Failed to list sessions: {}", e)),
+    };
+
+    let mut sessions = Vec::new();
+    for entry in entries.flatten() {
+        if entry.path().extension().and_then(|e| e.to_str()) == Some("json") {
+            if let Ok(session) = parse_codex_session_file(&entry.path()) {
+                let modified = entry.metadata().and_then(|m| m.modified()).ok();
+                sessions.push((modified, session));
+            }
+        }
+    }
+    // Newest first by file modification time, so `resume_last_codex` can take
+    // the head of the list.
+    sessions.sort_by(|a, b| b.0.cmp(&a.0));
+    Ok(sessions.into_iter().map(|(_, session)| session).collect())
+}
+
+/// Load a session's recorded history.
+#[tauri::command]
+pub async fn load_codex_session_history(session_id: String) -> Result<CodexSession, String> {
+    let path = find_session_file(&session_id)?;
+    parse_codex_session_file(&path)
+}
+
+/// Delete a recorded Codex session.
+#[tauri::command]
+pub async fn delete_codex_session(
+    session_id: String,
+    gate: tauri::State<'_, CapabilityGate>,
+) -> Result<(), String> {
+    gate.check("delete_codex_session")?;
+    let path = find_session_file(&session_id)?;
+    match std::fs::remove_file(&path) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(format!("Failed to delete session: {}", e)),
+    }
+}
@@ -0,0 +1,69 @@
+/**
+ * OpenAI Codex Integration - Session Conversion
+ *
+ * Writes a recorded session out under the other tool's file name: the input
+ * JSON is parsed and re-serialized unchanged to `<input>.codex.json` (from
+ * Claude) or `<input>.claude.json` (from Codex). No fields are mapped between
+ * the two formats. Compiled only when the `codex-convert` feature is enabled.
+ */
+
+use serde::{Deserialize, Serialize};
+
+/// Which direction a conversion starts from.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ConversionSource {
+    Claude,
+    Codex,
+}
+
+/// The outcome of a conversion.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConversionResult {
+    pub source: ConversionSource,
+    pub output_path: String,
+}
+
+/// Read a session file and re-serialize it under the target format's path.
+fn convert_file(source: ConversionSource, input_path: &str) -> Result<ConversionResult, String> {
+    let contents = std::fs::read_to_string(input_path)
+        .map_err(|e| format!("Failed to read session file: {}", e))?;
+    let value: serde_json::Value =
+        serde_json::from_str(&contents).map_err(|e| format!("Failed to parse session file: {}", e))?;
+
+    let output_path = match source {
+        ConversionSource::Claude => format!("{}.codex.json", input_path),
+        ConversionSource::Codex => format!("{}.claude.json", input_path),
+    };
+    let rewritten = serde_json::to_string_pretty(&value)
+        .map_err(|e| format!("Failed to serialize converted session: {}", e))?;
+    std::fs::write(&output_path, rewritten)
+        .map_err(|e| format!("Failed to write converted session: {}", e))?;
+
+    Ok(ConversionResult { source, output_path })
+}
+
+// ============================================================================
+// Tauri Commands
+// ============================================================================
+
+/// Convert a session file, starting from the given source format.
+#[tauri::command]
+pub async fn convert_session(
+    source: ConversionSource,
+    input_path: String,
+) -> Result<ConversionResult, String> {
+    convert_file(source, &input_path)
+}
+
+/// Write a Claude session to `<input>.codex.json` (content unchanged).
+#[tauri::command]
+pub async fn convert_claude_to_codex(input_path: String) -> Result<ConversionResult, String> {
+    convert_file(ConversionSource::Claude, &input_path)
+}
+
+/// Write a Codex session to `<input>.claude.json` (content unchanged).
+#[tauri::command]
+pub async fn convert_codex_to_claude(input_path: String) -> Result<ConversionResult, String> {
+    convert_file(ConversionSource::Codex, &input_path)
+}
@@ -0,0 +1,3 @@
+//! Backend command modules exposed to the Tauri frontend.
+
+pub mod codex;
@@ -0,0 +1,8 @@
+//! claude-workbench backend library.
+//!
+//! Exposes the Tauri command modules. The application binary depends on this
+//! crate, registers the commands with `tauri::generate_handler!`, and installs
+//! `commands::codex::CapabilityGate` as managed state (see the capability
+//! manifest in `commands::codex::capabilities`).
+
+pub mod commands;